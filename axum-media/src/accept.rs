@@ -41,3 +41,17 @@ impl From<Accept> for Option<String> {
         content_type.0
     }
 }
+
+impl Accept {
+    /// Negotiates the best `enabled` mime type for this header, per RFC 7231: media ranges are
+    /// parsed with their `q` parameter (default `1.0`, `q=0` excluded), then matched in
+    /// descending order of quality, breaking ties by specificity (`type/subtype` over `type/*`
+    /// over `*/*`). Returns `None` if the header is absent or matches none of `enabled`, in
+    /// which case callers typically fall back to a default type (as [`crate::AnyMedia`] does
+    /// with `application/json`).
+    pub fn negotiate(&self, enabled: &[mime::Mime]) -> Option<mime::Mime> {
+        self.0
+            .as_deref()
+            .and_then(|accept| crate::mimetypes::negotiate(accept, enabled))
+    }
+}
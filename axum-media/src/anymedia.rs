@@ -11,7 +11,7 @@ use bytes::{BufMut, Bytes, BytesMut};
 use serde::Serialize;
 use tracing::error;
 
-use crate::{mimetypes, AnyMediaDeserializeError, AnyMediaRejection, AnyMediaSerializeError};
+use crate::{mimetypes, stream, AnyMediaRejection, AnyMediaSerializeError};
 
 /// Automatic data extractor / response.
 ///
@@ -22,7 +22,9 @@ use crate::{mimetypes, AnyMediaDeserializeError, AnyMediaRejection, AnyMediaSeri
 /// [`AnyMediaRejection`] will be returned in case the body is not valid or `<T>` cannot be deserialized.
 ///
 /// When used as a response, second field is used to determine the type to Serialize to. If `None`,
-/// `application/json` will be used. Meant to be used with [`crate::Accept`] extractor.
+/// `application/json` will be used. Meant to be used with [`crate::Accept`] extractor, whose raw
+/// header value is parsed as a list of media ranges with `q` parameters and matched against the
+/// enabled serializers, honoring wildcards (`*/*`, `type/*`) and descending quality.
 ///
 /// ## Extractor example
 ///
@@ -100,6 +102,36 @@ impl<T> From<T> for AnyMedia<T> {
     }
 }
 
+impl<T> AnyMedia<T>
+where
+    T: Serialize + Send + 'static,
+{
+    /// Serializes `value` incrementally into the response body instead of buffering the whole
+    /// payload into memory first, trading constant memory use for giving up `Content-Length`
+    /// (the body becomes `Transfer-Encoding: chunked`, since the total size isn't known until
+    /// serialization finishes) and the ability to recover from a mid-stream serialization error,
+    /// which can only terminate the body early (logged via `tracing::error!`, same as the
+    /// buffered path, but after some bytes may already have reached the client). This is the
+    /// right tradeoff for multi-megabyte payloads where buffering the whole body would dominate
+    /// memory use; for small ones the buffered [`AnyMedia`] response is simpler and cheaper.
+    ///
+    /// `Content-Type` negotiation considers [`crate::MediaRegistry`]-registered formats too, but
+    /// only `application/json` is actually streamed; any other negotiated mime (built-in or
+    /// registered) falls back to the buffered [`AnyMedia`] response.
+    pub fn streamed(value: T, mime: Option<String>) -> axum::response::Response {
+        let resolved = mime
+            .as_deref()
+            .and_then(|accept| mimetypes::negotiate(accept, &mimetypes::enabled_mimes()))
+            .unwrap_or(mime::APPLICATION_JSON);
+
+        if resolved == mime::APPLICATION_JSON {
+            stream::json(value)
+        } else {
+            AnyMedia(value, mime).into_response()
+        }
+    }
+}
+
 impl<T> Deref for AnyMedia<T> {
     type Target = T;
 
@@ -121,33 +153,19 @@ where
     fn into_response(self) -> axum::response::Response {
         let mime = self
             .1
-            .map(|s| s.parse().unwrap_or(mime::APPLICATION_JSON))
+            .as_deref()
+            .and_then(|accept| mimetypes::negotiate(accept, &mimetypes::enabled_mimes()))
             .unwrap_or(mime::APPLICATION_JSON);
         let mut buf = BytesMut::with_capacity(128).writer();
 
-        let mut result: Option<Result<(), AnyMediaSerializeError>> =
-            match (mime.type_(), mime.subtype()) {
-                (mime::APPLICATION, mime::JSON) => {
-                    Some(mimetypes::serialize_json(&self.0, &mut buf))
-                }
-                #[cfg(feature = "urlencoded")]
-                (mime::APPLICATION, mime::WWW_FORM_URLENCODED) => {
-                    Some(mimetypes::serialize_urlencoded(&self.0, &mut buf))
-                }
-                _ => None,
-            };
-
-        if let None = result {
-            result = match (mime.type_(), mime.suffix()) {
-                #[cfg(feature = "urlencoded")]
-                (mime::APPLICATION, Some(mime::WWW_FORM_URLENCODED)) => {
-                    Some(mimetypes::serialize_urlencoded(&self.0, &mut buf))
-                }
-                _ => Some(mimetypes::serialize_json(&self.0, &mut buf)),
-            }
-        }
+        let result: Result<(), AnyMediaSerializeError> = crate::registry::MediaRegistry::global()
+            .read()
+            .unwrap()
+            .serialize(&mime, &self.0, &mut buf)
+            .or_else(|| mimetypes::serialize_builtin(&mime, &self.0, &mut buf))
+            .unwrap_or_else(|| mimetypes::serialize_json(&self.0, &mut buf));
 
-        if let Err(err) = result.unwrap() {
+        if let Err(err) = result {
             error!("{}", err);
             return (
                 StatusCode::INTERNAL_SERVER_ERROR,
@@ -190,32 +208,23 @@ where
 
         let bytes = Bytes::from_request(req, state).await?;
 
-        let result = match (mime.type_(), mime.subtype()) {
-            #[cfg(feature = "urlencoded")]
-            (mime::APPLICATION, mime::WWW_FORM_URLENCODED) => {
-                mimetypes::deserialize_urlencoded(&bytes)
-            }
-            _ => mimetypes::deserialize_json(&bytes),
-        };
-
-        match result {
-            Ok(data) => Ok(AnyMedia(data, None)),
-            Err(err) => {
+        if let Some(result) = crate::registry::MediaRegistry::global()
+            .read()
+            .unwrap()
+            .deserialize(&mime, &bytes)
+        {
+            return result.map(|data| AnyMedia(data, None)).map_err(|err| {
                 error!("{}", err);
-                match err {
-                    AnyMediaDeserializeError::JsonError(err) => match err.inner().classify() {
-                        serde_json::error::Category::Data => {
-                            Err(AnyMediaRejection::JsonDataError(err))
-                        }
-                        serde_json::error::Category::Syntax | serde_json::error::Category::Eof => {
-                            Err(AnyMediaRejection::JsonSyntaxError(err))
-                        }
-                        serde_json::error::Category::Io => unreachable!(),
-                    },
-                    #[cfg(feature = "urlencoded")]
-                    AnyMediaDeserializeError::UrlEncodedError(err) => Err(err.into()),
-                }
-            }
+                err.into()
+            });
         }
+
+        mimetypes::deserialize_builtin(&mime, &bytes)
+            .unwrap()
+            .map(|data| AnyMedia(data, None))
+            .map_err(|err| {
+                error!("{}", err);
+                err.into()
+            })
     }
 }
@@ -0,0 +1,120 @@
+use axum::{body::HttpBody, extract::FromRequest, http::Request, BoxError};
+use bytes::Bytes;
+
+use crate::{AnyMediaDeserializeError, AnyMediaRejection};
+
+/// Lazy, borrowing companion to [`crate::AnyMedia`]'s extractor.
+///
+/// Buffers the request body and resolves its `Content-Type` exactly like [`crate::AnyMedia`]
+/// does, but performs no parsing during extraction. Call [`Self::deserialize`] to decode the
+/// body into a target type, which may borrow directly from the stored bytes (e.g. `&str`/`&[u8]`
+/// fields marked `#[serde(borrow)]`), avoiding the `DeserializeOwned` bound and the allocations
+/// it forces on [`crate::AnyMedia`].
+///
+/// Because the deserialized value borrows from `self`, it cannot outlive the extractor value.
+/// Data and syntax errors also only surface at [`Self::deserialize`] call time, so a handler can
+/// inspect the raw bytes before committing to a target type.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// use axum_media::AnyMediaDeserializer;
+///
+/// #[derive(serde::Deserialize)]
+/// struct LoginData<'a> {
+///   email: &'a str,
+///   password: &'a str,
+/// }
+///
+/// async fn login(body: AnyMediaDeserializer) -> Result<String, axum_media::AnyMediaRejection> {
+///   // Inspect the body before committing to a target type.
+///   println!("Content-Type: {}", body.content_type());
+///
+///   let data: LoginData = body.deserialize()?;
+///   Ok(data.email.to_owned())
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct AnyMediaDeserializer {
+    bytes: Bytes,
+    mime: mime::Mime,
+}
+
+#[axum::async_trait]
+impl<S, B> FromRequest<S, B> for AnyMediaDeserializer
+where
+    B: HttpBody + Send + 'static,
+    B::Data: Send,
+    B::Error: Into<BoxError>,
+    S: Send + Sync,
+{
+    type Rejection = AnyMediaRejection;
+
+    async fn from_request(req: Request<B>, state: &S) -> Result<Self, Self::Rejection> {
+        let mime = req
+            .headers()
+            .get("content-type")
+            .map(|h| h.to_str().unwrap_or(""))
+            .unwrap_or("")
+            .parse()
+            .unwrap_or(mime::APPLICATION_JSON);
+
+        let bytes = Bytes::from_request(req, state).await?;
+
+        Ok(AnyMediaDeserializer { bytes, mime })
+    }
+}
+
+impl AnyMediaDeserializer {
+    /// The resolved `Content-Type`, so a handler can decide how (or whether) to deserialize
+    /// before calling [`Self::deserialize`].
+    pub fn content_type(&self) -> &mime::Mime {
+        &self.mime
+    }
+
+    /// The raw, buffered request body.
+    pub fn as_bytes(&self) -> &Bytes {
+        &self.bytes
+    }
+
+    /// Deserializes the stored body into `T`, dispatching on the resolved `Content-Type` the
+    /// same way [`crate::AnyMedia`]'s built-in formats do: json, urlencoded/yaml/cbor/msgpack
+    /// behind their feature flags, falling back to json for anything else. The returned value
+    /// may borrow from `self`.
+    ///
+    /// Unlike `AnyMedia`, this does not consult [`crate::MediaRegistry`] - a registered
+    /// deserializer only produces `DeserializeOwned` values, which conflicts with the borrowing
+    /// this type exists to support. A `Content-Type` registered there but not built in here still
+    /// falls back to json via the lenient default above.
+    pub fn deserialize<'de, T: serde::Deserialize<'de>>(
+        &'de self,
+    ) -> Result<T, AnyMediaRejection> {
+        let result = match (self.mime.type_(), self.mime.subtype().as_str()) {
+            #[cfg(feature = "urlencoded")]
+            (mime::APPLICATION, "x-www-form-urlencoded") => {
+                serde_urlencoded::from_bytes(&self.bytes).map_err(AnyMediaDeserializeError::from)
+            }
+            #[cfg(feature = "yaml")]
+            (mime::APPLICATION, "yaml") => {
+                let deserializer = serde_yaml::Deserializer::from_slice(&self.bytes);
+                serde_path_to_error::deserialize(deserializer)
+                    .map_err(AnyMediaDeserializeError::from)
+            }
+            #[cfg(feature = "cbor")]
+            (mime::APPLICATION, "cbor") => {
+                ciborium::from_reader(self.bytes.as_ref()).map_err(AnyMediaDeserializeError::from)
+            }
+            #[cfg(feature = "msgpack")]
+            (mime::APPLICATION, "msgpack") => {
+                rmp_serde::from_slice(&self.bytes).map_err(AnyMediaDeserializeError::from)
+            }
+            _ => {
+                let deserializer = &mut serde_json::Deserializer::from_slice(&self.bytes);
+                serde_path_to_error::deserialize(deserializer)
+                    .map_err(AnyMediaDeserializeError::from)
+            }
+        };
+
+        result.map_err(AnyMediaRejection::from)
+    }
+}
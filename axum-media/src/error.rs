@@ -16,14 +16,78 @@ pub enum AnyMediaRejection {
     #[cfg(feature = "yaml")]
     #[error("Failed to deserialize the yaml body into the target type: {0}")]
     YamlDataError(serde_path_to_error::Error<serde_yaml::Error>),
+    #[cfg(feature = "cbor")]
+    #[error("Failed to deserialize the cbor body into the target type: {0}")]
+    CborError(#[from] ciborium::de::Error<std::io::Error>),
+    #[cfg(feature = "msgpack")]
+    #[error("Failed to deserialize the msgpack body into the target type: {0}")]
+    MsgpackError(#[from] rmp_serde::decode::Error),
     #[error("{0}")]
     BytesRejection(#[from] BytesRejection),
+    /// The request's `Content-Type` is missing or names a media type no enabled deserializer
+    /// claims. Only returned when strict content-type enforcement is used.
+    #[error("Content-Type '{0}' is not supported")]
+    UnsupportedMediaType(String),
+    /// The request's `Content-Type` header is present but could not be parsed as a mime type at
+    /// all. Only returned when strict content-type enforcement is used; an absent header is
+    /// [`AnyMediaRejection::UnsupportedMediaType`] instead.
+    #[error("Content-Type '{0}' could not be parsed")]
+    MalformedContentType(String),
+    /// The request's `Accept` header matches no media type `AnyMedia` can serialize to. Only
+    /// returned when strict accept enforcement is used.
+    #[error("None of the requested media types are available, supported types are: {0}")]
+    NotAcceptable(String),
+    /// A [`crate::MediaRegistry`]-registered deserializer failed.
+    #[error("Failed to deserialize the request body using a registered format: {0}")]
+    RegistryError(#[from] RegistryError),
+}
+
+/// Boxed error from a [`crate::MediaRegistry`]-registered deserializer.
+#[derive(Debug)]
+pub struct RegistryError(pub(crate) Box<dyn std::error::Error + Send + Sync>);
+
+impl std::fmt::Display for RegistryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for RegistryError {}
+
+impl From<AnyMediaDeserializeError> for AnyMediaRejection {
+    fn from(err: AnyMediaDeserializeError) -> Self {
+        match err {
+            AnyMediaDeserializeError::JsonError(err) => match err.inner().classify() {
+                serde_json::error::Category::Data => AnyMediaRejection::JsonDataError(err),
+                serde_json::error::Category::Syntax | serde_json::error::Category::Eof => {
+                    AnyMediaRejection::JsonSyntaxError(err)
+                }
+                serde_json::error::Category::Io => unreachable!(),
+            },
+            #[cfg(feature = "urlencoded")]
+            AnyMediaDeserializeError::UrlEncodedError(err) => {
+                AnyMediaRejection::UrlEncodedError(err)
+            }
+            #[cfg(feature = "yaml")]
+            AnyMediaDeserializeError::YamlError(err) => AnyMediaRejection::YamlDataError(err),
+            #[cfg(feature = "cbor")]
+            AnyMediaDeserializeError::CborError(err) => AnyMediaRejection::CborError(err),
+            #[cfg(feature = "msgpack")]
+            AnyMediaDeserializeError::MsgpackError(err) => AnyMediaRejection::MsgpackError(err),
+        }
+    }
 }
 
 impl IntoResponse for AnyMediaRejection {
     fn into_response(self) -> axum::response::Response {
+        let status = match self {
+            AnyMediaRejection::UnsupportedMediaType(_) => StatusCode::UNSUPPORTED_MEDIA_TYPE,
+            AnyMediaRejection::NotAcceptable(_) => StatusCode::NOT_ACCEPTABLE,
+            _ => StatusCode::BAD_REQUEST,
+        };
+
         (
-            StatusCode::BAD_REQUEST,
+            status,
             [(header::CONTENT_TYPE, mime::UTF_8.to_string())],
             format!("{self}"),
         )
@@ -31,8 +95,9 @@ impl IntoResponse for AnyMediaRejection {
     }
 }
 
+/// Error produced by a serializer, built-in or [`crate::MediaRegistry`]-registered.
 #[derive(Debug, thiserror::Error)]
-pub(crate) enum AnyMediaSerializeError {
+pub enum AnyMediaSerializeError {
     #[error(transparent)]
     JsonError(#[from] serde_json::Error),
     #[cfg(feature = "urlencoded")]
@@ -44,6 +109,12 @@ pub(crate) enum AnyMediaSerializeError {
     #[cfg(feature = "xml")]
     #[error(transparent)]
     XmlError(#[from] serde_xml_rs::Error),
+    #[cfg(feature = "cbor")]
+    #[error(transparent)]
+    CborError(#[from] ciborium::ser::Error<std::io::Error>),
+    #[cfg(feature = "msgpack")]
+    #[error(transparent)]
+    MsgpackError(#[from] rmp_serde::encode::Error),
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -56,4 +127,10 @@ pub(crate) enum AnyMediaDeserializeError {
     #[cfg(feature = "yaml")]
     #[error(transparent)]
     YamlError(#[from] serde_path_to_error::Error<serde_yaml::Error>),
+    #[cfg(feature = "cbor")]
+    #[error(transparent)]
+    CborError(#[from] ciborium::de::Error<std::io::Error>),
+    #[cfg(feature = "msgpack")]
+    #[error(transparent)]
+    MsgpackError(#[from] rmp_serde::decode::Error),
 }
@@ -52,58 +52,32 @@
 //! }
 //!
 //! ```
-
-pub(crate) use axum::{
-    extract::rejection::BytesRejection,
-    http::{header, StatusCode},
-    response::IntoResponse,
-};
+//!
+//! ## Cargo features
+//!
+//! - `urlencoded` - `application/x-www-form-urlencoded` support via `serde_urlencoded`.
+//! - `yaml` - `application/yaml` support via `serde_yaml`.
+//! - `cbor` - `application/cbor` support via `ciborium`.
+//! - `msgpack` - `application/msgpack` support via `rmp-serde`.
+//!
+//! [`AnyMedia::streamed`] and [`crate::MediaRegistry`] are unconditionally available and pull in
+//! `tokio`, `tokio-stream` and `erased-serde` respectively. Only `application/json` is actually
+//! streamed by [`AnyMedia::streamed`]; every other format (including yaml/cbor/msgpack) falls
+//! back to the buffered [`AnyMedia`] response.
 
 pub(crate) mod accept;
 pub(crate) mod anymedia;
+pub(crate) mod deserializer;
+pub(crate) mod error;
 pub(crate) mod mimetypes;
+pub(crate) mod registry;
+pub(crate) mod strict;
+pub(crate) mod stream;
 
 pub use accept::Accept;
 pub use anymedia::AnyMedia;
-
-#[derive(Debug, thiserror::Error)]
-pub enum AnyMediaRejection {
-    #[error("Failed to deserialize the JSON body into the target type: {0}")]
-    JsonDataError(serde_path_to_error::Error<serde_json::Error>),
-    #[error("Failed to parse the request body as JSON: {0}")]
-    JsonSyntaxError(serde_path_to_error::Error<serde_json::Error>),
-    #[error("{0}")]
-    BytesRejection(#[from] BytesRejection),
-    #[cfg(feature = "urlencoded")]
-    #[error("{0}")]
-    UrlEncodedError(#[from] serde_urlencoded::de::Error),
-}
-
-impl IntoResponse for AnyMediaRejection {
-    fn into_response(self) -> axum::response::Response {
-        (
-            StatusCode::BAD_REQUEST,
-            [(header::CONTENT_TYPE, mime::UTF_8.to_string())],
-            format!("{self}"),
-        )
-            .into_response()
-    }
-}
-
-#[derive(Debug, thiserror::Error)]
-pub(crate) enum AnyMediaSerializeError {
-    #[error("{0}")]
-    JsonError(#[from] serde_json::Error),
-    #[cfg(feature = "urlencoded")]
-    #[error("{0}")]
-    UrlEncodedError(#[from] serde_urlencoded::ser::Error),
-}
-
-#[derive(Debug, thiserror::Error)]
-pub(crate) enum AnyMediaDeserializeError {
-    #[error("{0}")]
-    JsonError(#[from] serde_path_to_error::Error<serde_json::Error>),
-    #[cfg(feature = "urlencoded")]
-    #[error("{0}")]
-    UrlEncodedError(#[from] serde_urlencoded::de::Error),
-}
+pub use deserializer::AnyMediaDeserializer;
+pub use error::{AnyMediaRejection, AnyMediaSerializeError, RegistryError};
+pub(crate) use error::AnyMediaDeserializeError;
+pub use registry::MediaRegistry;
+pub use strict::AnyMediaStrict;
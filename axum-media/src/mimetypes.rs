@@ -1,5 +1,91 @@
+use std::sync::OnceLock;
+
 use bytes::{buf::Writer, Bytes, BytesMut};
 
+/// A single entry of a parsed `Accept` header, e.g. `application/json;q=0.8`.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct MediaRange {
+    type_: String,
+    subtype: String,
+    q: f32,
+}
+
+impl MediaRange {
+    /// Whether this range matches a concrete `mime::Mime`, honoring `*` wildcards.
+    fn matches(&self, mime: &mime::Mime) -> bool {
+        (self.type_ == "*" || self.type_ == mime.type_().as_str())
+            && (self.subtype == "*" || self.subtype == mime.subtype().as_str())
+    }
+
+    /// `*/*` is least specific, `type/*` is more specific, `type/subtype` is most specific.
+    fn specificity(&self) -> u8 {
+        match (self.type_.as_str(), self.subtype.as_str()) {
+            ("*", "*") => 0,
+            (_, "*") => 1,
+            _ => 2,
+        }
+    }
+}
+
+/// Parses an `Accept` header into media ranges sorted by descending `q`, then descending
+/// specificity, plus the set of ranges with `q=0`, which explicitly forbid the types they match
+/// rather than merely being absent from the accepted set (e.g. `application/json;q=0, */*` must
+/// not fall back to JSON via the `*/*` range). `type`/`subtype` are lowercased per RFC 7231's
+/// case-insensitive media-range comparison, matching `mime::Mime`, which always lowercases too.
+pub(crate) fn parse_accept(header: &str) -> (Vec<MediaRange>, Vec<MediaRange>) {
+    let mut accepted = Vec::new();
+    let mut forbidden = Vec::new();
+
+    for entry in header.split(',') {
+        let mut parts = entry.split(';');
+        let Some((type_, subtype)) = parts.next().and_then(|s| s.trim().split_once('/')) else {
+            continue;
+        };
+
+        let q = parts
+            .filter_map(|param| param.trim().strip_prefix("q="))
+            .next()
+            .and_then(|value| value.trim().parse::<f32>().ok())
+            .unwrap_or(1.0)
+            .clamp(0.0, 1.0);
+
+        let range = MediaRange {
+            type_: type_.trim().to_ascii_lowercase(),
+            subtype: subtype.trim().to_ascii_lowercase(),
+            q,
+        };
+
+        if q == 0.0 {
+            forbidden.push(range);
+        } else {
+            accepted.push(range);
+        }
+    }
+
+    accepted.sort_by(|a, b| {
+        b.q.partial_cmp(&a.q)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then(b.specificity().cmp(&a.specificity()))
+    });
+
+    (accepted, forbidden)
+}
+
+/// Picks the best `enabled` mime type for a given `Accept` header, per RFC 7231
+/// content negotiation. A `q=0` range excludes the types it matches from consideration, even if a
+/// broader wildcard range would otherwise accept them. Returns `None` if no enabled type satisfies
+/// any range.
+pub(crate) fn negotiate(accept: &str, enabled: &[mime::Mime]) -> Option<mime::Mime> {
+    let (accepted, forbidden) = parse_accept(accept);
+
+    accepted.iter().find_map(|range| {
+        enabled
+            .iter()
+            .find(|mime| range.matches(mime) && !forbidden.iter().any(|f| f.matches(mime)))
+            .cloned()
+    })
+}
+
 pub(crate) fn serialize_json<T: serde::Serialize>(
     data: &T,
     buf: &mut Writer<BytesMut>,
@@ -34,3 +120,197 @@ pub(crate) fn deserialize_urlencoded<T: serde::de::DeserializeOwned>(
 ) -> Result<T, crate::AnyMediaDeserializeError> {
     serde_urlencoded::from_bytes(bytes).map_err(|e| e.into())
 }
+
+#[cfg(feature = "yaml")]
+pub(crate) fn serialize_yaml<T: serde::Serialize>(
+    data: &T,
+    buf: &mut Writer<BytesMut>,
+) -> Result<(), crate::AnyMediaSerializeError> {
+    serde_yaml::to_writer(buf, data).map_err(|e| e.into())
+}
+
+#[cfg(feature = "yaml")]
+pub(crate) fn deserialize_yaml<T: serde::de::DeserializeOwned>(
+    bytes: &Bytes,
+) -> Result<T, crate::AnyMediaDeserializeError> {
+    let deserializer = serde_yaml::Deserializer::from_slice(bytes);
+
+    serde_path_to_error::deserialize(deserializer).map_err(|e| e.into())
+}
+
+#[cfg(feature = "cbor")]
+pub(crate) fn serialize_cbor<T: serde::Serialize>(
+    data: &T,
+    buf: &mut Writer<BytesMut>,
+) -> Result<(), crate::AnyMediaSerializeError> {
+    ciborium::into_writer(data, buf).map_err(|e| e.into())
+}
+
+#[cfg(feature = "cbor")]
+pub(crate) fn deserialize_cbor<T: serde::de::DeserializeOwned>(
+    bytes: &Bytes,
+) -> Result<T, crate::AnyMediaDeserializeError> {
+    ciborium::from_reader(bytes.as_ref()).map_err(|e| e.into())
+}
+
+#[cfg(feature = "msgpack")]
+pub(crate) fn serialize_msgpack<T: serde::Serialize>(
+    data: &T,
+    buf: &mut Writer<BytesMut>,
+) -> Result<(), crate::AnyMediaSerializeError> {
+    use std::io::Write;
+
+    rmp_serde::to_vec(data)
+        .map(|bytes| buf.write_all(&bytes).unwrap())
+        .map_err(|e| e.into())
+}
+
+#[cfg(feature = "msgpack")]
+pub(crate) fn deserialize_msgpack<T: serde::de::DeserializeOwned>(
+    bytes: &Bytes,
+) -> Result<T, crate::AnyMediaDeserializeError> {
+    rmp_serde::from_slice(bytes).map_err(|e| e.into())
+}
+
+static BUILTIN_MIMES: OnceLock<Vec<mime::Mime>> = OnceLock::new();
+
+/// Mime types `AnyMedia` can serialize/deserialize out of the box, used both to negotiate
+/// `Accept` and to validate an incoming `Content-Type`. Built once and reused, since every
+/// mime literal is re-parsed the first time this is called, not on every request.
+pub(crate) fn builtin_mimes() -> &'static [mime::Mime] {
+    BUILTIN_MIMES
+        .get_or_init(|| {
+            let mut mimes = vec![mime::APPLICATION_JSON];
+            #[cfg(feature = "urlencoded")]
+            mimes.push(mime::APPLICATION_WWW_FORM_URLENCODED);
+            #[cfg(feature = "yaml")]
+            mimes.push("application/yaml".parse().unwrap());
+            #[cfg(feature = "cbor")]
+            mimes.push("application/cbor".parse().unwrap());
+            #[cfg(feature = "msgpack")]
+            mimes.push("application/msgpack".parse().unwrap());
+            mimes
+        })
+        .as_slice()
+}
+
+/// Mime types available for negotiation: the built-in formats plus anything registered in
+/// [`crate::MediaRegistry::global`]. Used by `AnyMedia`, `AnyMedia::streamed` and
+/// `AnyMediaStrict` so the three negotiation sites can't drift apart.
+pub(crate) fn enabled_mimes() -> Vec<mime::Mime> {
+    let mut mimes = builtin_mimes().to_vec();
+    mimes.extend(crate::registry::MediaRegistry::global().read().unwrap().mimes());
+    mimes
+}
+
+/// Serializes `data` as `mime` using a built-in format, if `AnyMedia` ships one. `None` if no
+/// built-in format claims `mime`. Callers consult [`crate::MediaRegistry`] before this, so a
+/// registered format overrides the built-in one for the same mime.
+pub(crate) fn serialize_builtin<T: serde::Serialize>(
+    mime: &mime::Mime,
+    data: &T,
+    buf: &mut Writer<BytesMut>,
+) -> Option<Result<(), crate::AnyMediaSerializeError>> {
+    match (mime.type_(), mime.subtype().as_str()) {
+        (mime::APPLICATION, "json") => Some(serialize_json(data, buf)),
+        #[cfg(feature = "urlencoded")]
+        (mime::APPLICATION, "x-www-form-urlencoded") => Some(serialize_urlencoded(data, buf)),
+        #[cfg(feature = "yaml")]
+        (mime::APPLICATION, "yaml") => Some(serialize_yaml(data, buf)),
+        #[cfg(feature = "cbor")]
+        (mime::APPLICATION, "cbor") => Some(serialize_cbor(data, buf)),
+        #[cfg(feature = "msgpack")]
+        (mime::APPLICATION, "msgpack") => Some(serialize_msgpack(data, buf)),
+        _ => match (mime.type_(), mime.suffix().map(|name| name.as_str())) {
+            #[cfg(feature = "urlencoded")]
+            (mime::APPLICATION, Some("x-www-form-urlencoded")) => {
+                Some(serialize_urlencoded(data, buf))
+            }
+            _ => None,
+        },
+    }
+}
+
+/// Deserializes `bytes` as `mime` using a built-in format. Unlike [`serialize_builtin`], this
+/// always returns `Some`: an unrecognized `mime` falls back to JSON rather than returning `None`,
+/// so callers can't use the return value to decide whether to consult [`crate::MediaRegistry`];
+/// `AnyMedia` and `AnyMediaStrict` both check the registry first and call this only on a miss.
+pub(crate) fn deserialize_builtin<T: serde::de::DeserializeOwned>(
+    mime: &mime::Mime,
+    bytes: &Bytes,
+) -> Option<Result<T, crate::AnyMediaDeserializeError>> {
+    match (mime.type_(), mime.subtype().as_str()) {
+        #[cfg(feature = "urlencoded")]
+        (mime::APPLICATION, "x-www-form-urlencoded") => Some(deserialize_urlencoded(bytes)),
+        #[cfg(feature = "yaml")]
+        (mime::APPLICATION, "yaml") => Some(deserialize_yaml(bytes)),
+        #[cfg(feature = "cbor")]
+        (mime::APPLICATION, "cbor") => Some(deserialize_cbor(bytes)),
+        #[cfg(feature = "msgpack")]
+        (mime::APPLICATION, "msgpack") => Some(deserialize_msgpack(bytes)),
+        // Lenient default: an unrecognized Content-Type is treated as JSON (see AnyMediaStrict
+        // for an extractor that rejects instead).
+        _ => Some(deserialize_json(bytes)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::negotiate;
+
+    fn mime(s: &str) -> mime::Mime {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn picks_highest_q() {
+        let enabled = [mime("application/json"), mime("application/x-www-form-urlencoded")];
+
+        let picked = negotiate(
+            "application/json;q=0.5, application/x-www-form-urlencoded;q=0.9",
+            &enabled,
+        );
+
+        assert_eq!(picked, Some(mime("application/x-www-form-urlencoded")));
+    }
+
+    #[test]
+    fn breaks_q_ties_by_specificity() {
+        let enabled = [mime("application/x-www-form-urlencoded"), mime("application/json")];
+
+        let picked = negotiate("*/*, application/json", &enabled);
+
+        assert_eq!(picked, Some(mime("application/json")));
+    }
+
+    #[test]
+    fn wildcards_match_enabled_types() {
+        let enabled = [mime("application/json")];
+
+        assert_eq!(negotiate("text/html, */*;q=0.1", &enabled), Some(mime("application/json")));
+        assert_eq!(negotiate("application/*", &enabled), Some(mime("application/json")));
+    }
+
+    #[test]
+    fn q_zero_forbids_the_type_even_under_a_wildcard() {
+        let enabled = [mime("application/json")];
+
+        assert_eq!(negotiate("application/json;q=0, */*", &enabled), None);
+        assert_eq!(negotiate("application/json;q=0", &enabled), None);
+    }
+
+    #[test]
+    fn no_match_returns_none() {
+        let enabled = [mime("application/json")];
+
+        assert_eq!(negotiate("text/html", &enabled), None);
+    }
+
+    #[test]
+    fn matching_is_case_insensitive() {
+        let enabled = [mime("application/json")];
+
+        assert_eq!(negotiate("APPLICATION/JSON", &enabled), Some(mime("application/json")));
+        assert_eq!(negotiate("Application/Json;q=0", &enabled), None);
+    }
+}
@@ -0,0 +1,143 @@
+use std::{
+    collections::HashMap,
+    error::Error as StdError,
+    sync::{Arc, OnceLock, RwLock},
+};
+
+use bytes::{buf::Writer, Bytes, BytesMut};
+use mime::Mime;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::{error::RegistryError, AnyMediaSerializeError};
+
+type SerializeFn = Arc<
+    dyn Fn(&dyn erased_serde::Serialize, &mut Writer<BytesMut>) -> Result<(), AnyMediaSerializeError>
+        + Send
+        + Sync,
+>;
+
+type DeserializeFn =
+    Arc<dyn Fn(&Bytes) -> Result<Value, Box<dyn StdError + Send + Sync>> + Send + Sync>;
+
+#[derive(Clone)]
+struct Entry {
+    serialize: SerializeFn,
+    deserialize: DeserializeFn,
+}
+
+static GLOBAL: OnceLock<RwLock<MediaRegistry>> = OnceLock::new();
+
+/// Extensible registry of media-type serializers/deserializers, consulted by [`crate::AnyMedia`]
+/// alongside its built-in json/urlencoded/yaml/cbor/msgpack handlers.
+///
+/// The built-in formats are a fixed `match` over a handful of mime types; this registry lets
+/// downstream crates plug in formats `axum_media` doesn't ship (TOML, BSON, ...) without
+/// patching it. `AnyMedia`'s `IntoResponse` impl has no access to axum state, so
+/// registrations live in a process-wide registry reachable via [`Self::global`] - register your
+/// formats once during startup, before serving requests. A registered deserializer produces a
+/// [`serde_json::Value`] as an intermediate representation, which is then deserialized into the
+/// handler's target type; this keeps the registry free of per-format generic parameters at the
+/// cost of round-tripping through `Value` for non-JSON formats. Concretely, this means a
+/// registered binary format (CBOR, MessagePack, BSON, ...) will silently fail to deserialize any
+/// payload containing a construct `serde_json::Value` can't represent - raw byte strings (decoded
+/// as an array of numbers instead), non-string map keys, and non-finite floats (`NaN`/`Infinity`)
+/// all either lose their shape or error out during the `Value` conversion. Prefer the built-in
+/// `cbor`/`msgpack` feature flags, which deserialize directly into `T` and don't hit this, and
+/// reach for `register` mainly for formats without that restriction (TOML, which is text-based
+/// like JSON) or where the loss is acceptable.
+///
+/// ## Example
+///
+/// A real registration would delegate to a format crate (`toml`, `csv`, `bson`, ...); this one
+/// reverses the JSON text instead, so the example doesn't pull in an extra dependency.
+///
+/// ```rust,no_run
+/// use axum_media::MediaRegistry;
+///
+/// MediaRegistry::global().write().unwrap().register(
+///   "application/x-reversed-json".parse().unwrap(),
+///   |value, buf| {
+///     use std::io::Write;
+///     let json: String = serde_json::to_string(value)?.chars().rev().collect();
+///     buf.write_all(json.as_bytes()).unwrap();
+///     Ok(())
+///   },
+///   |bytes| {
+///     let json: Vec<u8> = bytes.iter().rev().copied().collect();
+///     Ok(serde_json::from_slice(&json)?)
+///   },
+/// );
+/// ```
+#[derive(Clone, Default)]
+pub struct MediaRegistry {
+    formats: HashMap<String, Entry>,
+}
+
+impl MediaRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The registry consulted by [`crate::AnyMedia`]'s extractor and responder, initialized
+    /// empty on first access.
+    pub fn global() -> &'static RwLock<MediaRegistry> {
+        GLOBAL.get_or_init(|| RwLock::new(MediaRegistry::default()))
+    }
+
+    /// Registers a serializer/deserializer pair for `mime`. A later call for the same
+    /// `type/subtype` replaces the earlier registration, so built-in types (`application/json`,
+    /// ...) can be overridden too.
+    pub fn register<S, D, E>(&mut self, mime: Mime, serialize: S, deserialize: D)
+    where
+        S: Fn(&dyn erased_serde::Serialize, &mut Writer<BytesMut>) -> Result<(), AnyMediaSerializeError>
+            + Send
+            + Sync
+            + 'static,
+        D: Fn(&Bytes) -> Result<Value, E> + Send + Sync + 'static,
+        E: StdError + Send + Sync + 'static,
+    {
+        self.formats.insert(
+            Self::key(&mime),
+            Entry {
+                serialize: Arc::new(serialize),
+                deserialize: Arc::new(move |bytes| deserialize(bytes).map_err(|e| Box::new(e) as _)),
+            },
+        );
+    }
+
+    fn key(mime: &Mime) -> String {
+        format!("{}/{}", mime.type_(), mime.subtype())
+    }
+
+    pub(crate) fn contains(&self, mime: &Mime) -> bool {
+        self.formats.contains_key(&Self::key(mime))
+    }
+
+    /// Mime types with a registered format, used to include them in `Accept` negotiation.
+    pub(crate) fn mimes(&self) -> Vec<Mime> {
+        self.formats.keys().filter_map(|key| key.parse().ok()).collect()
+    }
+
+    pub(crate) fn serialize(
+        &self,
+        mime: &Mime,
+        value: &dyn erased_serde::Serialize,
+        buf: &mut Writer<BytesMut>,
+    ) -> Option<Result<(), AnyMediaSerializeError>> {
+        self.formats
+            .get(&Self::key(mime))
+            .map(|entry| (entry.serialize)(value, buf))
+    }
+
+    pub(crate) fn deserialize<T: DeserializeOwned>(
+        &self,
+        mime: &Mime,
+        bytes: &Bytes,
+    ) -> Option<Result<T, RegistryError>> {
+        self.formats.get(&Self::key(mime)).map(|entry| {
+            let value = (entry.deserialize)(bytes).map_err(RegistryError)?;
+            serde_json::from_value(value).map_err(|e| RegistryError(Box::new(e)))
+        })
+    }
+}
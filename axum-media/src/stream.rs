@@ -0,0 +1,69 @@
+use std::io::{self, BufWriter, Write};
+
+use axum::{
+    body::StreamBody,
+    http::header,
+    response::{IntoResponse, Response},
+};
+use bytes::Bytes;
+use serde::Serialize;
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
+use tracing::error;
+
+/// Frames are forwarded in chunks this size, rather than one per `io::Write` call. `serde_json`
+/// writes in tiny fragments (braces, keys, separators), so without batching a large collection
+/// would produce millions of 1-3 byte frames and channel sends.
+const CHUNK_SIZE: usize = 8 * 1024;
+
+/// `io::Write` sink that forwards writes as body frames over a bounded channel, so a
+/// `serde::Serializer` writing into it streams the payload instead of buffering it. Wrapped in a
+/// [`BufWriter`] so frames are sent in [`CHUNK_SIZE`] batches instead of one per fragment.
+struct ChannelWriter {
+    tx: mpsc::Sender<Result<Bytes, io::Error>>,
+}
+
+impl Write for ChannelWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.tx
+            .blocking_send(Ok(Bytes::copy_from_slice(buf)))
+            .map_err(|_| io::Error::new(io::ErrorKind::BrokenPipe, "response body was dropped"))?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Serializes `data` as JSON into a chunked response body, flushing bytes in [`CHUNK_SIZE`]
+/// batches as `serde_json` produces them rather than collecting the whole payload into a buffer
+/// first. `Content-Length` is never set, since the total size isn't known until serialization
+/// finishes, and framing is left to hyper/axum rather than set by hand. Used by
+/// [`crate::AnyMedia::streamed`]; see its docs for the full tradeoffs.
+pub(crate) fn json<T>(data: T) -> Response
+where
+    T: Serialize + Send + 'static,
+{
+    let (tx, rx) = mpsc::channel::<Result<Bytes, io::Error>>(16);
+
+    tokio::task::spawn_blocking(move || {
+        let mut writer = BufWriter::with_capacity(CHUNK_SIZE, ChannelWriter { tx: tx.clone() });
+
+        let result = match data.serialize(&mut serde_json::Serializer::new(&mut writer)) {
+            Ok(()) => writer.flush().map_err(|e| e.to_string()),
+            Err(err) => Err(err.to_string()),
+        };
+
+        if let Err(err) = result {
+            error!("{}", err);
+            let _ = tx.blocking_send(Err(io::Error::new(io::ErrorKind::Other, err)));
+        }
+    });
+
+    (
+        [(header::CONTENT_TYPE, mime::APPLICATION_JSON.to_string())],
+        StreamBody::new(ReceiverStream::new(rx)),
+    )
+        .into_response()
+}
@@ -0,0 +1,164 @@
+use std::ops::{Deref, DerefMut};
+
+use axum::{
+    body::HttpBody,
+    extract::FromRequest,
+    http::{header, HeaderValue, Request, StatusCode},
+    response::IntoResponse,
+    BoxError,
+};
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::Serialize;
+use tracing::error;
+
+use crate::{mimetypes, AnyMediaRejection, AnyMediaSerializeError};
+
+/// Strict sibling of [`crate::AnyMedia`].
+///
+/// Where [`crate::AnyMedia`] silently falls back to `application/json` for a missing, malformed
+/// or unknown `Content-Type`/`Accept`, `AnyMediaStrict` rejects instead: extraction fails with
+/// [`AnyMediaRejection::UnsupportedMediaType`] (`415`) when the header is absent or names a type
+/// no enabled deserializer claims, with [`AnyMediaRejection::MalformedContentType`] (`400`) when
+/// the header is present but isn't a parseable mime type at all, and the response is
+/// `406 Not Acceptable` with a `Content-Type`-listing body when the `Accept` header matches no
+/// enabled serializer. Use this when an API should reject e.g. `text/plain` or
+/// `multipart/form-data` instead of feeding them to the JSON parser.
+///
+/// This is a separate type rather than a generic marker (e.g. `AnyMedia<T, Strict>`) so that
+/// strictness is visible in a handler's signature without an extra type parameter on the common
+/// case, matching how [`crate::AnyMediaDeserializer`] is also a sibling type of `AnyMedia` rather
+/// than a mode switch on it.
+#[derive(Debug, Clone, Default)]
+pub struct AnyMediaStrict<T>(pub T, pub Option<String>);
+
+impl<T> From<T> for AnyMediaStrict<T> {
+    fn from(data: T) -> Self {
+        AnyMediaStrict(data, None)
+    }
+}
+
+impl<T> Deref for AnyMediaStrict<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for AnyMediaStrict<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T> IntoResponse for AnyMediaStrict<T>
+where
+    T: Serialize,
+{
+    fn into_response(self) -> axum::response::Response {
+        let enabled = mimetypes::enabled_mimes();
+
+        let mime = match self.1.as_deref() {
+            None => mime::APPLICATION_JSON,
+            Some(accept) => match mimetypes::negotiate(accept, &enabled) {
+                Some(mime) => mime,
+                None => {
+                    let available = enabled
+                        .iter()
+                        .map(|mime| mime.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", ");
+
+                    return AnyMediaRejection::NotAcceptable(available).into_response();
+                }
+            },
+        };
+
+        let mut buf = BytesMut::with_capacity(128).writer();
+
+        let result: Result<(), AnyMediaSerializeError> = crate::registry::MediaRegistry::global()
+            .read()
+            .unwrap()
+            .serialize(&mime, &self.0, &mut buf)
+            .or_else(|| mimetypes::serialize_builtin(&mime, &self.0, &mut buf))
+            .unwrap_or_else(|| mimetypes::serialize_json(&self.0, &mut buf));
+
+        if let Err(err) = result {
+            error!("{}", err);
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                [(
+                    header::CONTENT_TYPE,
+                    HeaderValue::from_static(mime::TEXT_PLAIN_UTF_8.as_ref()),
+                )],
+                err.to_string(),
+            )
+                .into_response();
+        }
+
+        (
+            [(header::CONTENT_TYPE, mime.to_string())],
+            buf.into_inner().freeze(),
+        )
+            .into_response()
+    }
+}
+
+#[axum::async_trait]
+impl<T, S, B> FromRequest<S, B> for AnyMediaStrict<T>
+where
+    T: serde::de::DeserializeOwned,
+    B: HttpBody + Send + 'static,
+    B::Data: Send,
+    B::Error: Into<BoxError>,
+    S: Send + Sync,
+{
+    type Rejection = AnyMediaRejection;
+
+    async fn from_request(req: Request<B>, state: &S) -> Result<Self, Self::Rejection> {
+        let content_type = match req.headers().get("content-type") {
+            None => "",
+            Some(h) => h.to_str().map_err(|_| {
+                AnyMediaRejection::MalformedContentType(
+                    String::from_utf8_lossy(h.as_bytes()).into_owned(),
+                )
+            })?,
+        };
+
+        if content_type.is_empty() {
+            return Err(AnyMediaRejection::UnsupportedMediaType(content_type.to_owned()));
+        }
+
+        let mime: mime::Mime = content_type
+            .parse()
+            .map_err(|_| AnyMediaRejection::MalformedContentType(content_type.to_owned()))?;
+
+        if !mimetypes::enabled_mimes()
+            .iter()
+            .any(|enabled| enabled.type_() == mime.type_() && enabled.subtype() == mime.subtype())
+        {
+            return Err(AnyMediaRejection::UnsupportedMediaType(content_type.to_owned()));
+        }
+
+        let bytes = Bytes::from_request(req, state).await?;
+
+        if let Some(result) = crate::registry::MediaRegistry::global()
+            .read()
+            .unwrap()
+            .deserialize(&mime, &bytes)
+        {
+            return result.map(|data| AnyMediaStrict(data, None)).map_err(|err| {
+                error!("{}", err);
+                err.into()
+            });
+        }
+
+        mimetypes::deserialize_builtin(&mime, &bytes)
+            .unwrap()
+            .map(|data| AnyMediaStrict(data, None))
+            .map_err(|err| {
+                error!("{}", err);
+                err.into()
+            })
+    }
+}
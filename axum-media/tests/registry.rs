@@ -0,0 +1,72 @@
+use axum::{response::IntoResponse, routing::get, Router};
+use axum_media::{Accept, AnyMedia, MediaRegistry};
+use axum_test_helper::TestClient;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+struct TestData {
+    test: bool,
+}
+
+fn register_custom_format() {
+    MediaRegistry::global().write().unwrap().register(
+        "application/x-test-format".parse().unwrap(),
+        |value, buf| {
+            use std::io::Write;
+            let json = serde_json::to_string(value)?;
+            buf.write_all(format!("custom:{json}").as_bytes()).unwrap();
+            Ok(())
+        },
+        |bytes| {
+            let json = bytes.strip_prefix(b"custom:").unwrap_or(bytes);
+            serde_json::from_slice::<serde_json::Value>(json)
+        },
+    );
+}
+
+#[tokio::test]
+async fn it_should_serialize_through_a_registered_format() {
+    register_custom_format();
+
+    async fn handler(accept: Accept) -> impl IntoResponse {
+        AnyMedia(TestData { test: true }, accept.into())
+    }
+
+    let app = Router::new().route("/", get(handler));
+
+    let client = TestClient::new(app);
+    let res = client
+        .get("/")
+        .header("Accept", "application/x-test-format")
+        .send()
+        .await;
+
+    assert_eq!(res.status(), 200);
+    assert_eq!(
+        res.headers().get("content-type").unwrap(),
+        "application/x-test-format"
+    );
+    assert_eq!(res.text().await, r#"custom:{"test":true}"#);
+}
+
+#[tokio::test]
+async fn it_should_deserialize_through_a_registered_format() {
+    register_custom_format();
+
+    async fn handler(AnyMedia(data, _): AnyMedia<TestData>) -> impl IntoResponse {
+        data.test.to_string()
+    }
+
+    let app = Router::new().route("/", get(handler));
+
+    let client = TestClient::new(app);
+    let res = client
+        .get("/")
+        .body(r#"custom:{"test":true}"#)
+        .header("Content-Type", "application/x-test-format")
+        .send()
+        .await;
+
+    assert_eq!(res.status(), 200);
+    assert_eq!(res.text().await, "true");
+}
@@ -0,0 +1,95 @@
+use axum::{response::IntoResponse, routing::get, Router};
+use axum_media::{Accept, AnyMediaStrict};
+use axum_test_helper::TestClient;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+struct TestData {
+    test: bool,
+}
+
+#[tokio::test]
+async fn it_should_reject_missing_content_type_with_415() {
+    async fn handler(AnyMediaStrict(data, _): AnyMediaStrict<TestData>) -> impl IntoResponse {
+        data.test.to_string()
+    }
+
+    let app = Router::new().route("/", get(handler));
+
+    let client = TestClient::new(app);
+    let res = client.get("/").body(r#"{"test":true}"#).send().await;
+
+    assert_eq!(res.status(), 415);
+}
+
+#[tokio::test]
+async fn it_should_reject_unknown_content_type_with_415() {
+    async fn handler(AnyMediaStrict(data, _): AnyMediaStrict<TestData>) -> impl IntoResponse {
+        data.test.to_string()
+    }
+
+    let app = Router::new().route("/", get(handler));
+
+    let client = TestClient::new(app);
+    let res = client
+        .get("/")
+        .body(r#"{"test":true}"#)
+        .header("Content-Type", "text/plain")
+        .send()
+        .await;
+
+    assert_eq!(res.status(), 415);
+}
+
+#[tokio::test]
+async fn it_should_reject_malformed_content_type_with_400() {
+    async fn handler(AnyMediaStrict(data, _): AnyMediaStrict<TestData>) -> impl IntoResponse {
+        data.test.to_string()
+    }
+
+    let app = Router::new().route("/", get(handler));
+
+    let client = TestClient::new(app);
+    let res = client
+        .get("/")
+        .body(r#"{"test":true}"#)
+        .header("Content-Type", "not a mime type")
+        .send()
+        .await;
+
+    assert_eq!(res.status(), 400);
+}
+
+#[tokio::test]
+async fn it_should_accept_a_known_content_type() {
+    async fn handler(AnyMediaStrict(data, _): AnyMediaStrict<TestData>) -> impl IntoResponse {
+        data.test.to_string()
+    }
+
+    let app = Router::new().route("/", get(handler));
+
+    let client = TestClient::new(app);
+    let res = client
+        .get("/")
+        .body(r#"{"test":true}"#)
+        .header("Content-Type", "application/json")
+        .send()
+        .await;
+
+    assert_eq!(res.status(), 200);
+    assert_eq!(res.text().await, "true");
+}
+
+#[tokio::test]
+async fn it_should_reject_unacceptable_accept_with_406() {
+    async fn handler(accept: Accept) -> impl IntoResponse {
+        AnyMediaStrict(TestData { test: true }, accept.into())
+    }
+
+    let app = Router::new().route("/", get(handler));
+
+    let client = TestClient::new(app);
+    let res = client.get("/").header("Accept", "text/html").send().await;
+
+    assert_eq!(res.status(), 406);
+}